@@ -11,6 +11,11 @@
 /// This macro ensures that certain checks are enforced during the compilation process,
 /// and not at runtime, improving program safety by catching issues as early as possible.
 ///
+/// When `CONFIG_RUST_BUILD_ASSERT_ALLOW` is enabled, or in a non-optimized (debug assertions
+/// on) build, the compiler and optimizer are not trusted to eliminate the call, so this
+/// expands to an ordinary [`panic!`] instead of the link-time failure described above. This
+/// keeps the fast development/debug cycle useful while still catching the error.
+///
 /// # Examples
 ///
 /// ```
@@ -26,11 +31,18 @@
 #[macro_export]
 macro_rules! build_error {
     () => {{
-        $crate::build_error("")
+        $crate::build_error!("")
     }};
     ($msg:expr) => {{
         // Ensure that the error message is more informative
-        $crate::build_error(concat!("[Build Error] ", $msg, " at line: ", stringify!($line)))
+        if cfg!(any(CONFIG_RUST_BUILD_ASSERT_ALLOW, debug_assertions)) {
+            // In a non-optimized (debug) build the optimizer may fail to
+            // eliminate this call, which would fail the link, so fall back
+            // to a runtime panic carrying the same message instead.
+            panic!("{}", concat!("[Build Error] ", $msg, " at ", file!(), ":", line!(), ":", column!()))
+        } else {
+            $crate::build_error(concat!("[Build Error] ", $msg, " at ", file!(), ":", line!(), ":", column!()))
+        }
     }};
 }
 
@@ -43,7 +55,11 @@ macro_rules! build_error {
 /// This macro helps you assert conditions that are crucial to the correctness of your program
 /// before the code even runs, minimizing the potential for runtime errors.
 ///
-/// [`static_assert!`] should be preferred to `build_assert!` whenever possible, as it provides 
+/// Like [`build_error!`], this falls back to a runtime [`panic!`] when
+/// `CONFIG_RUST_BUILD_ASSERT_ALLOW` is enabled or in a non-optimized (debug assertions on)
+/// build, so that an unoptimized condition does not hard-fail the link while iterating.
+///
+/// [`static_assert!`] should be preferred to `build_assert!` whenever possible, as it provides
 /// better performance and clarity in simpler cases.
 ///
 /// # Examples
@@ -84,21 +100,95 @@ macro_rules! build_assert {
     // Simplified condition, only condition provided
     ($cond:expr $(,)?) => {{
         if !$cond {
-            $crate::build_error(concat!("assertion failed: ", stringify!($cond), " at line: ", stringify!($line)));
+            $crate::build_error!(concat!("assertion failed: ", stringify!($cond)));
         }
     }};
-    
+
     // Condition with a custom message
     ($cond:expr, $msg:expr) => {{
         if !$cond {
-            $crate::build_error(concat!($msg, " at line: ", stringify!($line)));
+            $crate::build_error!($msg);
         }
     }};
-    
+
     // Condition with more context information (useful for debugging)
     ($cond:expr, $msg:expr, $context:expr) => {{
         if !$cond {
-            $crate::build_error(concat!($msg, " at line: ", stringify!($line), " - Context: ", $context));
+            $crate::build_error!(concat!($msg, " - Context: ", $context));
         }
     }};
     }
+
+/// Asserts that two expressions are equal to each other, at compile time.
+///
+/// Equivalent to [`build_assert!`]`(left == right)`, but with a message that names both sides
+/// when the condition is not guaranteed to hold, akin to [`assert_eq!`]. The message holds the
+/// source text of the two expressions, not their values.
+///
+/// [`static_assert!`] should be preferred to `build_assert_eq!` whenever possible, as it provides
+/// better performance and clarity in simpler cases.
+///
+/// # Examples
+///
+/// ```
+/// fn foo<const N: usize, T>() {
+///     build_assert_eq!(N, core::mem::align_of::<T>());
+/// }
+/// ```
+///
+/// [`static_assert!`]: crate::static_assert!
+#[macro_export]
+macro_rules! build_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        $crate::build_assert!(
+            $left == $right,
+            concat!(
+                "assertion failed: `(left == right)`: ",
+                stringify!($left),
+                " == ",
+                stringify!($right)
+            )
+        );
+    }};
+
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        $crate::build_assert!($left == $right, $($arg)+);
+    }};
+}
+
+/// Asserts that two expressions are not equal to each other, at compile time.
+///
+/// Equivalent to [`build_assert!`]`(left != right)`, but with a message that names both sides
+/// when the condition is not guaranteed to hold, akin to [`assert_ne!`]. The message holds the
+/// source text of the two expressions, not their values.
+///
+/// [`static_assert!`] should be preferred to `build_assert_ne!` whenever possible, as it provides
+/// better performance and clarity in simpler cases.
+///
+/// # Examples
+///
+/// ```
+/// fn foo<const N: usize>() {
+///     build_assert_ne!(N, 0);
+/// }
+/// ```
+///
+/// [`static_assert!`]: crate::static_assert!
+#[macro_export]
+macro_rules! build_assert_ne {
+    ($left:expr, $right:expr $(,)?) => {{
+        $crate::build_assert!(
+            $left != $right,
+            concat!(
+                "assertion failed: `(left != right)`: ",
+                stringify!($left),
+                " != ",
+                stringify!($right)
+            )
+        );
+    }};
+
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        $crate::build_assert!($left != $right, $($arg)+);
+    }};
+}